@@ -1,7 +1,7 @@
 use ak09915_rs::Ak09915;
 use ak09915_rs::Mode;
-use linux_embedded_hal::I2cdev;
 use clap::Parser;
+use linux_embedded_hal::{Delay, I2cdev};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -15,34 +15,34 @@ fn main() {
 
     let args = Args::parse();
     let dev = I2cdev::new(args.device).unwrap();
-    let mut sensor = Ak09915::new(dev);
+    let mut sensor = Ak09915::new(dev, Delay);
 
     if sensor.self_test().unwrap(){
-        println!("Self test -  OK");     
+        println!("Self test -  OK");
     }
 
-    println!("Test 5 single measurement, and 5 without set single measurement(test data ready"); 
+    println!("Test 5 single measurement, and 5 without set single measurement(test data ready");
     for _n in 1..=5 {
-        sensor.set_mode(Mode::Single).unwrap();
+        sensor.set_mode(Mode::SingleMeasurement).unwrap();
         if sensor.is_data_ready().unwrap(){
-            let (x, y, z) = sensor.read_mag().unwrap();
-            println!("Magnetometer: x={}, y={}, z={}", x, y, z);
+            let (x, y, z, status) = sensor.read_mag().unwrap();
+            println!("Magnetometer: x={}, y={}, z={} ({:?})", x, y, z, status);
         }
         }
-    println!("Test 5 measurement, without set single measurement(test data ready");        
+    println!("Test 5 measurement, without set single measurement(test data ready");
     for _n in 1..=5 {
         if sensor.is_data_ready().unwrap(){
-            let (x, y, z) = sensor.read_mag().unwrap();
-            println!("Magnetometer: x={}, y={}, z={}", x, y, z);
+            let (x, y, z, status) = sensor.read_mag().unwrap();
+            println!("Magnetometer: x={}, y={}, z={} ({:?})", x, y, z, status);
         }
         }
-    println!("Test 5 measurement, using continuous mode"); 
-    sensor.set_mode(Mode::Cont200Hz).unwrap();
+    println!("Test 5 measurement, using continuous mode");
+    sensor.set_mode(Mode::ContMeasurement200).unwrap();
     for _n in 1..=5 {
         if sensor.is_data_ready().unwrap(){
-            let (x, y, z) = sensor.read_mag().unwrap();
-            println!("Magnetometer: x={}, y={}, z={}", x, y, z);
+            let (x, y, z, status) = sensor.read_mag().unwrap();
+            println!("Magnetometer: x={}, y={}, z={} ({:?})", x, y, z, status);
         }
         std::thread::sleep(std::time::Duration::from_micros(1000));
         }
-}
\ No newline at end of file
+}
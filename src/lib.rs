@@ -1,5 +1,7 @@
-use embedded_hal::blocking::i2c::{Write, WriteRead};
+#![cfg_attr(not(test), no_std)]
+
 use embedded_hal::blocking::delay::DelayUs;
+use embedded_hal::blocking::i2c::{Write, WriteRead};
 
 const AK09915_ADDRESS: u8 = 0x0C;
 
@@ -32,11 +34,142 @@ const AK09915_MODE_CONTINUOUS_200HZ: u8 = 0x0A;
 const AK09915_MODE_CONTINUOUS_1HZ: u8 = 0x0C;
 const AK09915_MODE_SELFTEST: u8 = 0x10;
 
-pub struct Ak09915<I2C> {
+// CNTL2 FIFO control
+const AK09915_CNTL2_FIFO_EN: u8 = 0x80; // FIFO enable bit
+
+// CNTL2 noise-suppression filter level, bits [6:5]
+const AK09915_CNTL2_NSF_SHIFT: u8 = 5;
+const AK09915_CNTL2_NSF_MASK: u8 = 0x03;
+
+// ST1 FIFO count field
+const AK09915_ST1_FNUM_SHIFT: u8 = 2;
+const AK09915_ST1_FNUM_MASK: u8 = 0x1F;
+
+// WIA Register values
+const AK09915_COMPANY_ID: u8 = 0x48;
+const AK09915_DEVICE_ID: u8 = 0x10;
+
+// Sensitivity, in microtesla per LSB, of the 16-bit magnetic data registers.
+const AK09915_SENSITIVITY_UT_PER_LSB: f32 = 0.15;
+
+// 9.3.5. Temperature Sensor Output:
+//   Temperature[degC] = 25 - (TMPS[7:0] - TMPS25) / Digit
+// where TMPS25 (the raw output at 25 degC) is 0 and Digit is 1.6 LSB/degC.
+const AK09915_TEMP_OFFSET_C: f32 = 25.0;
+const AK09915_TEMP_DIGIT_PER_C: f32 = 1.6;
+
+// ST2 Register bits
+const AK09915_ST2_DERR: u8 = 0x04; // Data error
+const AK09915_ST2_HOFL: u8 = 0x08; // Magnetic sensor overflow
+
+/// Error returned by the driver, wrapping either an I2C bus error or a
+/// protocol-level condition reported by the sensor itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error<E> {
+    /// The underlying I2C bus returned an error.
+    I2c(E),
+    /// The sensor reported a data error (ST2 DERR bit) for the last sample.
+    DataError,
+    /// WIA1/WIA2 didn't match the expected AK09915 company/device ID.
+    InvalidDevice,
+}
+
+impl<E> From<E> for Error<E> {
+    fn from(error: E) -> Self {
+        Error::I2c(error)
+    }
+}
+
+/// Whether a magnetic sample was within the sensor's linear range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MagStatus {
+    /// Measurement is within the sensor's linear range.
+    Ok,
+    /// Measurement exceeded the sensor's linear range (ST2 HOFL bit).
+    Overflow,
+}
+
+pub struct Ak09915<I2C, D> {
     pub i2c: I2C,
     pub address: u8,
+    delay: D,
+    /// Hard-iron offset, in microtesla, subtracted from each reading in
+    /// `read_mag_calibrated` before the soft-iron matrix is applied.
+    /// Defaults to zero.
+    bias: [f32; 3],
+    /// Soft-iron correction matrix applied to the bias-corrected reading.
+    /// Defaults to the identity matrix.
+    soft_iron: [[f32; 3]; 3],
+    /// Currently configured CNTL2 settings, kept so that `set_mode` and
+    /// `set_fifo` can change one field and re-apply the others unchanged.
+    config: MeasurementConfig,
 }
 
+/// Identity matrix used as the default (uncalibrated) soft-iron correction.
+const IDENTITY_MATRIX: [[f32; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Hard-iron bias vector and soft-iron correction matrix, as returned by
+/// `collect_calibration` and accepted by `set_calibration`.
+pub type CalibrationParams = ([f32; 3], [[f32; 3]; 3]);
+
+/// Derives a hard-iron bias and diagonal soft-iron scale matrix from the
+/// per-axis min/max range of a set of samples. The bias is the midpoint of
+/// each axis's range; the soft-iron scale normalizes each axis against the
+/// average range so that a perfectly spherical field reads equally on all
+/// three axes.
+fn calibration_from_range(min: [f32; 3], max: [f32; 3]) -> CalibrationParams {
+    let range = [max[0] - min[0], max[1] - min[1], max[2] - min[2]];
+    let bias = [
+        (max[0] + min[0]) / 2.0,
+        (max[1] + min[1]) / 2.0,
+        (max[2] + min[2]) / 2.0,
+    ];
+    let avg_range = (range[0] + range[1] + range[2]) / 3.0;
+
+    let mut matrix = IDENTITY_MATRIX;
+    for axis in 0..3 {
+        matrix[axis][axis] = if range[axis] > 0.0 {
+            avg_range / range[axis]
+        } else {
+            1.0
+        };
+    }
+
+    (bias, matrix)
+}
+
+#[cfg(test)]
+mod calibration_tests {
+    use super::*;
+
+    #[test]
+    fn bias_is_midpoint_of_range() {
+        let (bias, _) = calibration_from_range([-10.0, -20.0, -30.0], [10.0, 0.0, 10.0]);
+        assert_eq!(bias, [0.0, -10.0, -10.0]);
+    }
+
+    #[test]
+    fn equal_ranges_yield_identity_matrix() {
+        let (_, matrix) = calibration_from_range([-10.0, -10.0, -10.0], [10.0, 10.0, 10.0]);
+        assert_eq!(matrix, IDENTITY_MATRIX);
+    }
+
+    #[test]
+    fn narrower_axis_gets_scaled_up() {
+        // ranges are 20, 10, 20 -> avg_range = 50/3, so axis 1 (range 10)
+        // is scaled by (50/3)/10 = 5/3.
+        let (_, matrix) = calibration_from_range([-10.0, -5.0, -10.0], [10.0, 5.0, 10.0]);
+        assert!((matrix[1][1] - 5.0 / 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn zero_range_axis_falls_back_to_unity_scale() {
+        let (_, matrix) = calibration_from_range([0.0, -10.0, -10.0], [0.0, 10.0, 10.0]);
+        assert_eq!(matrix[0][0], 1.0);
+    }
+}
+
+#[derive(Clone, Copy)]
 pub enum Mode {
     PowerDown,
     SingleMeasurement,
@@ -49,13 +182,110 @@ pub enum Mode {
     SelfTest,
 }
 
+fn mode_bits(mode: Mode) -> u8 {
+    match mode {
+        Mode::PowerDown => AK09915_MODE_POWERDOWN,
+        Mode::SingleMeasurement => AK09915_MODE_SINGLE,
+        Mode::ContMeasurement10 => AK09915_MODE_CONTINUOUS_10HZ,
+        Mode::ContMeasurement20 => AK09915_MODE_CONTINUOUS_20HZ,
+        Mode::ContMeasurement50 => AK09915_MODE_CONTINUOUS_50HZ,
+        Mode::ContMeasurement100 => AK09915_MODE_CONTINUOUS_100HZ,
+        Mode::ContMeasurement200 => AK09915_MODE_CONTINUOUS_200HZ,
+        Mode::ContMeasurement1 => AK09915_MODE_CONTINUOUS_1HZ,
+        Mode::SelfTest => AK09915_MODE_SELFTEST,
+    }
+}
+
+/// Noise-suppression filter level (CNTL2 NSF bits), trading noise floor
+/// against current draw: `Low` draws the least current, `VeryHigh`
+/// suppresses the most noise.
+#[derive(Clone, Copy)]
+pub enum NoiseSuppressionFilter {
+    Low,
+    Mid,
+    High,
+    VeryHigh,
+}
+
+fn nsf_bits(nsf: NoiseSuppressionFilter) -> u8 {
+    let level = match nsf {
+        NoiseSuppressionFilter::Low => 0,
+        NoiseSuppressionFilter::Mid => 1,
+        NoiseSuppressionFilter::High => 2,
+        NoiseSuppressionFilter::VeryHigh => 3,
+    };
+    (level & AK09915_CNTL2_NSF_MASK) << AK09915_CNTL2_NSF_SHIFT
+}
+
+/// Full CNTL2 configuration, set atomically in one register write:
+/// measurement mode, noise-suppression filter level, and FIFO enable.
+#[derive(Clone, Copy)]
+pub struct MeasurementConfig {
+    pub mode: Mode,
+    pub nsf: NoiseSuppressionFilter,
+    pub fifo: bool,
+}
+
+impl MeasurementConfig {
+    /// A config for `mode` with the default noise-suppression filter
+    /// (`Low`) and FIFO disabled.
+    pub fn new(mode: Mode) -> Self {
+        Self {
+            mode,
+            nsf: NoiseSuppressionFilter::Low,
+            fifo: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod cntl2_tests {
+    use super::*;
+
+    #[test]
+    fn mode_bits_match_cntl2_mode_field() {
+        assert_eq!(mode_bits(Mode::PowerDown), AK09915_MODE_POWERDOWN);
+        assert_eq!(
+            mode_bits(Mode::ContMeasurement200),
+            AK09915_MODE_CONTINUOUS_200HZ
+        );
+    }
+
+    #[test]
+    fn nsf_bits_are_packed_into_bits_6_5() {
+        assert_eq!(nsf_bits(NoiseSuppressionFilter::Low), 0x00);
+        assert_eq!(nsf_bits(NoiseSuppressionFilter::Mid), 0x20);
+        assert_eq!(nsf_bits(NoiseSuppressionFilter::VeryHigh), 0x60);
+    }
 
-impl<I2C, E> Ak09915<I2C>
+    #[test]
+    fn nsf_bits_dont_collide_with_mode_or_fifo_bits() {
+        for nsf in [
+            NoiseSuppressionFilter::Low,
+            NoiseSuppressionFilter::Mid,
+            NoiseSuppressionFilter::High,
+            NoiseSuppressionFilter::VeryHigh,
+        ] {
+            assert_eq!(nsf_bits(nsf) & AK09915_MODE_SELFTEST, 0);
+            assert_eq!(nsf_bits(nsf) & AK09915_CNTL2_FIFO_EN, 0);
+        }
+    }
+}
+
+impl<I2C, D, E> Ak09915<I2C, D>
 where
     I2C: Write<Error = E> + WriteRead<Error = E>,
+    D: DelayUs<u32>,
 {
-    pub fn new(i2c: I2C) -> Self {
-        Self { i2c , address : AK09915_ADDRESS }
+    pub fn new(i2c: I2C, delay: D) -> Self {
+        Self {
+            i2c,
+            address: AK09915_ADDRESS,
+            delay,
+            bias: [0.0, 0.0, 0.0],
+            soft_iron: IDENTITY_MATRIX,
+            config: MeasurementConfig::new(Mode::PowerDown),
+        }
     }
 
     fn write_register(&mut self, register: u8, value: u8) -> Result<(), E> {
@@ -70,45 +300,74 @@ where
             .and(Ok(buffer[0]))
     }
 
-    pub fn init(&mut self) -> Result<(), E> {
+    pub fn init(&mut self) -> Result<(), Error<E>> {
+        self.verify_id()?;
         // Soft reset device and put on continuous measurement
         self.reset()?;
         self.set_mode(Mode::ContMeasurement50)?;
         Ok(())
     }
 
-    pub fn reset(&mut self) -> Result<(), E> {
+    /// Reads the WIA1/WIA2 company and device ID registers.
+    pub fn who_am_i(&mut self) -> Result<(u8, u8), Error<E>> {
+        let company_id = self.read_register(AK09915_REG_WIA1)?;
+        let device_id = self.read_register(AK09915_REG_WIA2)?;
+        Ok((company_id, device_id))
+    }
+
+    /// Verifies that the device answering on the bus is an AK09915,
+    /// returning `Error::InvalidDevice` otherwise.
+    pub fn verify_id(&mut self) -> Result<(), Error<E>> {
+        let (company_id, device_id) = self.who_am_i()?;
+        if company_id != AK09915_COMPANY_ID || device_id != AK09915_DEVICE_ID {
+            return Err(Error::InvalidDevice);
+        }
+        Ok(())
+    }
+
+    pub fn reset(&mut self) -> Result<(), Error<E>> {
         // Soft reset device
         self.write_register(AK09915_REG_CNTL3, 0x01)?;
         Ok(())
     }
 
-    pub fn set_mode(&mut self, mode: Mode) -> Result<(), E> {
-        let reg = match mode {
-            Mode::PowerDown => AK09915_MODE_POWERDOWN,
-            Mode::SingleMeasurement => AK09915_MODE_SINGLE,
-            Mode::ContMeasurement10 => AK09915_MODE_CONTINUOUS_10HZ,
-            Mode::ContMeasurement20 => AK09915_MODE_CONTINUOUS_20HZ,
-            Mode::ContMeasurement50 => AK09915_MODE_CONTINUOUS_50HZ,
-            Mode::ContMeasurement100 => AK09915_MODE_CONTINUOUS_100HZ,
-            Mode::ContMeasurement200 => AK09915_MODE_CONTINUOUS_200HZ,
-            Mode::ContMeasurement1 => AK09915_MODE_CONTINUOUS_1HZ,
-            Mode::SelfTest => AK09915_MODE_SELFTEST,
-        };
-        //When user wants to change operation mode,
-        //transit to power-down mode first and then transit to other modes. After Power-down mode is set, at least 100
-        //µs (Twait) is needed before setting another mode.
-        self.write_register(AK09915_REG_CNTL2, AK09915_MODE_POWERDOWN)?;
-        
-        //not working, dirty solution
-        // let mut delay = DelayUs::
-        // delay.delay_us(100u32);
-        std::thread::sleep(std::time::Duration::from_micros(100));
+    /// Sets mode, noise-suppression filter, and FIFO enable atomically in
+    /// a single CNTL2 write.
+    //When user wants to change operation mode,
+    //transit to power-down mode first and then transit to other modes. After Power-down mode is set, at least 100
+    //µs (Twait) is needed before setting another mode.
+    pub fn configure(&mut self, config: MeasurementConfig) -> Result<(), Error<E>> {
+        let reg = mode_bits(config.mode)
+            | nsf_bits(config.nsf)
+            | if config.fifo { AK09915_CNTL2_FIFO_EN } else { 0 };
 
+        self.write_register(AK09915_REG_CNTL2, AK09915_MODE_POWERDOWN)?;
+        self.delay.delay_us(100u32);
         self.write_register(AK09915_REG_CNTL2, reg)?;
+
+        self.config = config;
         Ok(())
     }
 
+    /// Sets the measurement mode, keeping the current NSF level and FIFO
+    /// setting unchanged. Kept for backward compatibility; prefer
+    /// `configure` to set all of CNTL2 atomically.
+    pub fn set_mode(&mut self, mode: Mode) -> Result<(), Error<E>> {
+        self.configure(MeasurementConfig {
+            mode,
+            ..self.config
+        })
+    }
+
+    /// Enables or disables the internal FIFO (CNTL2 FIFO enable bit),
+    /// re-applying the currently configured measurement mode and NSF level.
+    pub fn set_fifo(&mut self, enabled: bool) -> Result<(), Error<E>> {
+        self.configure(MeasurementConfig {
+            fifo: enabled,
+            ..self.config
+        })
+    }
+
     // 9.4.4.1. Self-test Sequence:
     //   1. Set Power-down mode (MODE[4:0] bits = "00000").
     //   2. Set Self-test mode (MODE[4:0] bits = "10000").
@@ -124,41 +383,203 @@ where
     //     - HY[15:0] bits: -200 ≤ HY ≤ +200
     //     - HZ[15:0] bits: -800 ≤ HZ ≤ -200
 
-    pub fn self_test(&mut self) -> Result<(), E> {
+    pub fn self_test(&mut self) -> Result<bool, Error<E>> {
         self.set_mode(Mode::SelfTest)?;
 
         self.is_data_ready()?;
 
-        let (hx, hy, hz) = self.read_mag()?;
+        let (hx, hy, hz, _status) = self.read_mag()?;
 
         // Self-test judgment
-        if (hx >= -200 && hx <= 200) && (hy >= -200 && hy <= 200) && (hz >= -800 && hz <= -200) {
-            println!("Self-test passed \nMagnetometer: x={}, y={}, z={}", hx, hy, hz);
-        } else {
-            println!("Self-test failed");
-        }
-        Ok(())
+        Ok((hx >= -200 && hx <= 200) && (hy >= -200 && hy <= 200) && (hz >= -800 && hz <= -200))
     }
 
-    pub fn is_data_ready(&mut self) -> Result<bool, E> {
+    pub fn is_data_ready(&mut self) -> Result<bool, Error<E>> {
         let mut retries = 10;
         while retries > 0 {
             let status = self.read_register(AK09915_REG_ST1)?;
             if (status & 0x01) != 0 {
                 return Ok(true); // Data ready
             }
-            std::thread::sleep(std::time::Duration::from_micros(100));
+            self.delay.delay_us(100u32);
             retries -= 1;
         }
         Ok(false) // Data not ready after retries
     }
 
-    pub fn read_mag(&mut self) -> Result<(i16, i16, i16), E> {
-        let mut buffer: [u8; 6] = [0u8; 6];
-        self.i2c.write_read(self.address, &[AK09915_REG_HXL], &mut buffer)?;
+    /// Reads a magnetic sample along with its status.
+    ///
+    /// This burst-reads HXL..ST2 in one I2C transaction: the datasheet
+    /// requires ST2 to be read to release the data registers for the next
+    /// measurement. The DERR bit (read error) is surfaced as
+    /// `Error::DataError`; the HOFL bit (sensor overflow) is surfaced as
+    /// `MagStatus::Overflow` alongside the (still returned) raw counts.
+    pub fn read_mag(&mut self) -> Result<(i16, i16, i16, MagStatus), Error<E>> {
+        let mut buffer: [u8; 8] = [0u8; 8];
+        self.i2c
+            .write_read(self.address, &[AK09915_REG_HXL], &mut buffer)?;
         let x = i16::from_le_bytes([buffer[0], buffer[1]]);
         let y = i16::from_le_bytes([buffer[2], buffer[3]]);
         let z = i16::from_le_bytes([buffer[4], buffer[5]]);
-        Ok((x, y, z))
+        let st2 = buffer[(AK09915_REG_ST2 - AK09915_REG_HXL) as usize];
+
+        if st2 & AK09915_ST2_DERR != 0 {
+            return Err(Error::DataError);
+        }
+
+        let status = if st2 & AK09915_ST2_HOFL != 0 {
+            MagStatus::Overflow
+        } else {
+            MagStatus::Ok
+        };
+
+        Ok((x, y, z, status))
+    }
+
+    /// Drains the internal FIFO (filled while `set_fifo(true)` is active in
+    /// a continuous mode) into `out`, one burst-read per buffered sample.
+    /// Returns how many complete samples were retrieved, which is
+    /// `min(buffered_count, out.len())`.
+    ///
+    /// If a burst read fails partway through (e.g. `Error::DataError` from
+    /// ST2 DERR on a later sample), the samples already written into
+    /// `out[..k]` for the preceding iterations are left in place but are
+    /// discarded along with the error, since there is no way to report a
+    /// partial count alongside `Err`. Callers that care about not losing
+    /// those samples should drain with a smaller `out` slice (or one sample
+    /// at a time via `read_mag`) so a failure only costs the current read.
+    pub fn read_fifo(
+        &mut self,
+        out: &mut [(i16, i16, i16, MagStatus)],
+    ) -> Result<usize, Error<E>> {
+        let st1 = self.read_register(AK09915_REG_ST1)?;
+        let count = fifo_count(st1).min(out.len());
+
+        for slot in out.iter_mut().take(count) {
+            *slot = self.read_mag()?;
+        }
+
+        Ok(count)
+    }
+
+    /// Reads a magnetic sample scaled to microtesla using the AK09915's
+    /// 0.15 µT/LSB sensitivity, for callers who don't want raw counts.
+    pub fn read_mag_ut(&mut self) -> Result<(f32, f32, f32), Error<E>> {
+        let (x, y, z, _status) = self.read_mag()?;
+        Ok((
+            x as f32 * AK09915_SENSITIVITY_UT_PER_LSB,
+            y as f32 * AK09915_SENSITIVITY_UT_PER_LSB,
+            z as f32 * AK09915_SENSITIVITY_UT_PER_LSB,
+        ))
+    }
+
+    /// Sets the hard-iron bias and soft-iron correction matrix applied by
+    /// `read_mag_calibrated`.
+    pub fn set_calibration(&mut self, bias: [f32; 3], matrix: [[f32; 3]; 3]) {
+        self.bias = bias;
+        self.soft_iron = matrix;
+    }
+
+    /// Reads a magnetic sample in microtesla with hard-iron/soft-iron
+    /// correction applied: `corrected = matrix * (raw_ut - bias)`.
+    pub fn read_mag_calibrated(&mut self) -> Result<(f32, f32, f32), Error<E>> {
+        let (x, y, z) = self.read_mag_ut()?;
+        let centered = [x - self.bias[0], y - self.bias[1], z - self.bias[2]];
+
+        let m = &self.soft_iron;
+        let corrected = [
+            m[0][0] * centered[0] + m[0][1] * centered[1] + m[0][2] * centered[2],
+            m[1][0] * centered[0] + m[1][1] * centered[1] + m[1][2] * centered[2],
+            m[2][0] * centered[0] + m[2][1] * centered[1] + m[2][2] * centered[2],
+        ];
+
+        Ok((corrected[0], corrected[1], corrected[2]))
+    }
+
+    /// Samples `samples` magnetic readings (intended to be taken while the
+    /// device is rotated through all orientations) and derives a hard-iron
+    /// bias and a diagonal soft-iron scale matrix from the per-axis
+    /// min/max range. The bias is the midpoint of each axis's range; the
+    /// soft-iron scale normalizes each axis against the average range so
+    /// that a perfectly spherical field reads equally on all three axes.
+    ///
+    /// Returns the computed `(bias, matrix)` without applying them --
+    /// call `set_calibration` with the result to activate it.
+    pub fn collect_calibration(&mut self, samples: usize) -> Result<CalibrationParams, Error<E>> {
+        let mut min = [f32::MAX; 3];
+        let mut max = [f32::MIN; 3];
+
+        let mut collected = 0;
+        while collected < samples {
+            if !self.is_data_ready()? {
+                continue;
+            }
+            let (x, y, z) = self.read_mag_ut()?;
+            let sample = [x, y, z];
+            for axis in 0..3 {
+                min[axis] = min[axis].min(sample[axis]);
+                max[axis] = max[axis].max(sample[axis]);
+            }
+            collected += 1;
+        }
+
+        Ok(calibration_from_range(min, max))
+    }
+
+    /// Reads the on-chip temperature sensor (TMPS register) and converts
+    /// the signed raw output into degrees Celsius.
+    ///
+    /// The AK family datasheets only specify this register for factory
+    /// shipment/self-test verification, not for field use -- treat it as a
+    /// coarse indicator for drift compensation rather than an accurate
+    /// ambient temperature reading.
+    pub fn read_temperature(&mut self) -> Result<f32, Error<E>> {
+        let raw = self.read_register(AK09915_REG_TMPS)? as i8;
+        Ok(raw_to_celsius(raw))
+    }
+}
+
+fn raw_to_celsius(raw: i8) -> f32 {
+    AK09915_TEMP_OFFSET_C - (raw as f32) / AK09915_TEMP_DIGIT_PER_C
+}
+
+/// Extracts the buffered sample count (FNUM field) from an ST1 byte.
+fn fifo_count(st1: u8) -> usize {
+    ((st1 >> AK09915_ST1_FNUM_SHIFT) & AK09915_ST1_FNUM_MASK) as usize
+}
+
+#[cfg(test)]
+mod fifo_tests {
+    use super::*;
+
+    #[test]
+    fn zero_fnum_is_empty() {
+        assert_eq!(fifo_count(0x00), 0);
+    }
+
+    #[test]
+    fn fnum_field_is_extracted_from_bits_6_2() {
+        // DRDY=1, DOR=0, FNUM=0b00101 (5 buffered samples)
+        assert_eq!(fifo_count(0b0001_0101), 5);
+    }
+
+    #[test]
+    fn fnum_extraction_ignores_drdy_and_dor_bits() {
+        assert_eq!(fifo_count(0b0111_1111), (AK09915_ST1_FNUM_MASK) as usize);
+    }
+}
+
+#[cfg(test)]
+mod temperature_tests {
+    use super::*;
+
+    #[test]
+    fn zero_raw_is_25_degrees() {
+        assert_eq!(raw_to_celsius(0), 25.0);
+    }
+
+    #[test]
+    fn positive_raw_is_below_25_degrees() {
+        assert_eq!(raw_to_celsius(16), 15.0);
     }
 }
\ No newline at end of file